@@ -1,33 +1,92 @@
 #![no_std]
-#![feature(const_fn)]
 
 //! Linked List implementation that uses a static array as backing memory for an arbitrary number of linked lists.
 //!
 //! # Usage
-//! To create a linked list you first have to create a [`StaticLinkedListBackingArray`] passing to it an array of `u8`.
-//! Then, you can create any number of lists backed by that array using [`new_list()`]. Note that creating arrays of zero-sized types
-//! is not possible.
+//! To create a linked list you first have to create a [`StaticLinkedListBackingArray`], which owns storage for exactly
+//! `N` entries of type `T`. Then, you can create any number of lists backed by that array using [`new_list()`]. Note that
+//! creating arrays of zero-sized types is not possible.
 //!
-//! Since the created lists share the backing arrays underlying memory the total number of entries across all lists cannot exceed the
-//! array's capacity.
+//! Since the created lists share the backing array's underlying memory the total number of entries across all lists cannot
+//! exceed the array's capacity of `N`.
 //!
-//! The list needs some memory in the buffer for its metadata (pointers to the next element). If the buffer used to create a 
-//! [`StaticLinkedListBackingArray`] is not big enough to hold `n` entries of data *plus* list metadata, the array will only have a
-//! capacity of `n - 1` entries. The remaining bytes will be wasted. The [`capacity_for()`] function was designed to calculate the
-//! exact amount of bytes necessary for holding `n` entries of data.
+//! # Pinning invariant
+//! The backing array owns its storage inline and the lists carved from it hold raw pointers into that storage. Once the
+//! first list has been created the array **must not be moved** — returning it by value, boxing it, or pushing it into a
+//! `Vec` relocates the storage and leaves every list's `head`/`tail` (and the free list) dangling. Create the array in its
+//! final location (a `static`, a `let` that outlives the lists, or behind a pin) and only then call [`new_list()`].
+//!
+//! There is no compile-time enforcement of this — the array isn't pinned and no lifetime ties a list back to it. As a
+//! best-effort backstop, [`new_list()`] records the array's address the first time it runs and panics if a later call
+//! observes a different one, which catches the common case of moving the array and then creating another list from it.
+//! It cannot catch every misuse: dereferencing an already-issued list's pointers after the move is undefined behavior
+//! before any check of ours runs. Treat the panic as a bug report, not as the safety mechanism.
 //!
-//! [`capacity_for()`]: struct.StaticLinkedListBackingArray.html#method.capacity_for
 //! [`StaticLinkedListBackingArray`]: struct.StaticLinkedListBackingArray.html
 //! [`new_list()`]: struct.StaticLinkedListBackingArray.html#method.new_list
 
-use core::ptr::null_mut;
-use core::mem::size_of;
+use core::ptr::{null_mut, addr_of_mut};
+use core::mem::MaybeUninit;
+use core::ops::ControlFlow;
 
 pub use crate::error::Error;
 use crate::error::Error::*;
 
+#[cfg(feature = "cas")]
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering::{Acquire, Release, Relaxed}};
+
 mod error;
 
+// Tagged free-list head used by the `cas` feature. The head pointer and a
+// monotonically incrementing tag are packed into a single `usize` so the whole
+// word can be swapped with a single `compare_exchange`. The tag defeats the ABA
+// problem: a node freed and re-pushed between a pop's load and its CAS carries a
+// stale tag, so the CAS fails and the pop retries.
+//
+// The tag lives in the spare *low* bits that `Linked<T>`'s alignment guarantees
+// are zero in any real node pointer (a `Linked<T>` holds raw pointers, so it is
+// at least pointer-aligned). Deriving the width from `align_of` keeps this sound
+// on 32-bit targets, where assuming unused *high* pointer bits would truncate
+// real addresses. Natural pointer alignment alone would only buy 3 low bits
+// (8-byte alignment on 64-bit targets), wrapping the tag every 8 pops/pushes —
+// far too narrow a window to rely on under real contention, so `Linked<T>` is
+// explicitly overaligned to 64 bytes under `cas` (see its definition below),
+// widening the tag to 6 bits and the window to 64.
+#[cfg(feature = "cas")]
+fn tag_mask<T>() -> usize where T: Clear {
+	core::mem::align_of::<Linked<T>>() - 1
+}
+
+#[cfg(feature = "cas")]
+fn pack<T>(ptr: *mut Linked<T>, tag: usize) -> usize where T: Clear {
+	let mask = tag_mask::<T>();
+	(ptr as usize & !mask) | (tag & mask)
+}
+
+#[cfg(feature = "cas")]
+fn unpack_ptr<T>(word: usize) -> *mut Linked<T> where T: Clear {
+	(word & !tag_mask::<T>()) as *mut Linked<T>
+}
+
+#[cfg(feature = "cas")]
+fn unpack_tag<T>(word: usize) -> usize where T: Clear {
+	word & tag_mask::<T>()
+}
+
+// View a node's `next` field as an atomic so the lock-free free list can load and
+// store it without a data race. `AtomicPtr<T>` is `repr(transparent)` over `*mut
+// T`, so this reinterpretation is sound as long as every concurrent access to a
+// free node's `next` goes through here (list-owned nodes are never shared).
+//
+// `'a` is conjured from the raw pointer rather than pinned to `'static`: a
+// `'static` return type would require `Linked<T>: 'static`, i.e. `T: 'static`,
+// which callers never promise and don't need to — every caller uses the
+// reference immediately and lets it expire long before the node itself does.
+#[cfg(feature = "cas")]
+unsafe fn next_atomic<'a, T>(node: *mut Linked<T>) -> &'a AtomicPtr<Linked<T>> where T: Clear {
+	&*(core::ptr::addr_of!((*node).next) as *const AtomicPtr<Linked<T>>)
+}
+
 /// Clear the memory of an instance of type T. Types stored in lists must implement this trait. 
 /// [`clear()`] is called before a memory block is returned to the backing array's memory pool.
 ///
@@ -36,9 +95,15 @@ pub trait Clear {
 	fn clear(&mut self);
 }
 
-// One link in the list
+// One link in the list.
+//
+// Overaligned to 64 bytes under `cas` so the tagged free-list head (see the
+// module-level notes above `tag_mask`) gets 6 spare low bits instead of
+// whatever `T` happens to leave at natural pointer alignment.
+#[cfg_attr(feature = "cas", repr(align(64)))]
 struct Linked<T> where T: Clear {
     next: *mut Linked<T>,
+    prev: *mut Linked<T>,
     data: T,
 }
 
@@ -49,16 +114,16 @@ impl<T> Clear for Linked<T> where T: Clear {
 }
 
 /// A singly-linked list for elements of type T backed by a static array.
-pub struct StaticLinkedList<'buf, T> where T: Clear {
+pub struct StaticLinkedList<T, const N: usize> where T: Clear {
 	size: usize,
 
 	head: *mut Linked<T>,
 	tail: *mut Linked<T>,
 
-	array: *mut StaticLinkedListBackingArray<'buf, T>
+	array: *mut StaticLinkedListBackingArray<T, N>
 }
 
-impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
+impl<T, const N: usize> StaticLinkedList<T, N> where T: Clear {
 	/// Returns the number of elements stored in the list.
 	pub fn size(&self) -> usize {
 		self.size
@@ -82,10 +147,14 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 		} else {
 			unsafe {
 				if let Some(new) = (*self.array).get_free() {
-					(*new).data = data;
+					// SAFETY: `new` is a fresh slot from the free list; its `data`
+					// bytes are uninitialized garbage, so a plain assignment would drop
+					// that garbage as `T`. `write` initializes without reading it.
+					addr_of_mut!((*new).data).write(data);
+					(*new).prev = self.tail;
 
 					if self.tail.is_null() {
-						self.head = new;						
+						self.head = new;
 					} else {
 						(*self.tail).next = new;
 					}
@@ -109,12 +178,19 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 		} else {
 			unsafe {
 				if let Some(new) = (*self.array).get_free() {
-					(*new).data = data;
+					// SAFETY: `new` is a fresh slot from the free list; its `data`
+					// bytes are uninitialized garbage, so a plain assignment would drop
+					// that garbage as `T`. `write` initializes without reading it.
+					addr_of_mut!((*new).data).write(data);
+					(*new).prev = null_mut();
 
 					if self.tail.is_null() {
 						self.tail = new;
 					}
 					(*new).next = self.head;
+					if !self.head.is_null() {
+						(*self.head).prev = new;
+					}
 					self.head = new;
 					self.size += 1;
 
@@ -126,6 +202,24 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 		}
 	}
 
+	/// Appends every element produced by `iter`, stopping when the iterator is
+	/// exhausted or the backing array is `OutOfSpace`.
+	///
+	/// Returns the number of elements inserted on success. Because the pool is
+	/// finite a panicking `collect`/`FromIterator` would be unsound, so this is the
+	/// fallible form: if the array fills up it returns `Err(OutOfSpace)` and leaves
+	/// the elements inserted so far in the list rather than rolling back.
+	pub fn try_extend<I>(&mut self, iter: I) -> Result<usize, Error>
+		where I: IntoIterator<Item = T>
+	{
+		let mut inserted = 0;
+		for item in iter {
+			self.append(item)?;
+			inserted += 1;
+		}
+		Ok(inserted)
+	}
+
 	/// Returns a reference to the first element in the list.
 	pub fn head(&self) -> Option<&T> {
 		if self.head.is_null() {
@@ -178,6 +272,9 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 
 					let to_remove = self.head;
 					self.head = (*self.head).next;
+					if !self.head.is_null() {
+						(*self.head).prev = null_mut();
+					}
 
 					p.free(to_remove.as_mut().unwrap());
 					self.size -= 1;
@@ -211,6 +308,9 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 					if !prev.is_null() {
 						(*prev).next = (*cursor).next;
 					}
+					if !(*cursor).next.is_null() {
+						(*(*cursor).next).prev = prev;
+					}
 					(*self.array).free(to_remove.as_mut().unwrap());
 					self.size -= 1;
 				} else {
@@ -221,11 +321,355 @@ impl<'buf, T> StaticLinkedList<'buf, T> where T: Clear {
 			Ok(self)
 		}
 	}
+
+	/// Returns an iterator yielding `&mut T` so entries can be updated in place
+	/// without removing them from the list.
+	pub fn iter_mut(&mut self) -> StaticLinkedListMutIterator<'_, T> {
+		StaticLinkedListMutIterator {
+			cursor: self.head,
+			_phantom: core::marker::PhantomData,
+		}
+	}
+
+	/// Inserts `data` so that it ends up at position `index`.
+	///
+	/// `index == 0` prepends, `index == size()` appends, and any `index` past the
+	/// end yields `IndexOutOfBounds`. Interior inserts grab a node from the backing
+	/// array and splice it in by fixing the four surrounding `next`/`prev` links.
+	pub fn insert_at(&mut self, index: usize, data: T) -> Result<(), Error> {
+		if index > self.size {
+			return Err(IndexOutOfBounds);
+		}
+		if index == 0 {
+			return self.prepend(data).map(|_| ());
+		}
+		if index == self.size {
+			return self.append(data);
+		}
+		if self.array.is_null() {
+			return Err(NullPointer);
+		}
+
+		unsafe {
+			let mut prev = self.head;
+			for _ in 0..index - 1 {
+				prev = (*prev).next;
+			}
+
+			let new = match (*self.array).get_free() {
+				Some(new) => new,
+				None => return Err(OutOfSpace),
+			};
+			// SAFETY: `new` is a fresh slot from the free list; its `data` bytes
+			// are uninitialized garbage, so a plain assignment would drop that
+			// garbage as `T`. `write` initializes without reading it.
+			addr_of_mut!((*new).data).write(data);
+
+			let next = (*prev).next;
+			(*new).prev = prev;
+			(*new).next = next;
+			(*prev).next = new;
+			(*next).prev = new; // `next` is non-null because index < size
+			self.size += 1;
+
+			Ok(())
+		}
+	}
+
+	/// Removes the `len` elements starting at `start`, returning them to the pool
+	/// and returning the number of elements actually removed.
+	///
+	/// `start` past the end yields `IndexOutOfBounds`; a `len` that reaches beyond
+	/// the tail is clamped to the remaining elements.
+	pub fn remove_range(&mut self, start: usize, len: usize) -> Result<usize, Error> {
+		if start > self.size {
+			return Err(IndexOutOfBounds);
+		}
+		if len == 0 {
+			return Ok(0);
+		}
+		if start >= self.size {
+			return Err(IndexOutOfBounds);
+		}
+		if self.array.is_null() {
+			return Err(NullPointer);
+		}
+
+		unsafe {
+			let mut prev = null_mut();
+			let mut cursor = self.head;
+			for _ in 0..start {
+				prev = cursor;
+				cursor = (*cursor).next;
+			}
+
+			let count = if len < self.size - start { len } else { self.size - start };
+			let mut removed = 0;
+			while removed < count {
+				let next = (*cursor).next;
+				(*self.array).free(cursor.as_mut().unwrap());
+				cursor = next;
+				removed += 1;
+			}
+
+			// `cursor` now points at the first surviving node after the run (or null).
+			if prev.is_null() {
+				self.head = cursor;
+			} else {
+				(*prev).next = cursor;
+			}
+			if cursor.is_null() {
+				self.tail = prev;
+			} else {
+				(*cursor).prev = prev;
+			}
+			self.size -= count;
+
+			Ok(count)
+		}
+	}
+
+	/// Applies `f` to each element in order, stopping early and returning
+	/// `ControlFlow::Break` as soon as `f` does.
+	///
+	/// Lets callers search or validate a list without allocating an index or
+	/// walking every element when an early answer is available.
+	pub fn try_for_each<B>(&self, mut f: impl FnMut(&T) -> ControlFlow<B>) -> ControlFlow<B> {
+		let mut cursor = self.head;
+		unsafe {
+			while !cursor.is_null() {
+				match f(&(*cursor).data) {
+					ControlFlow::Continue(()) => {},
+					ControlFlow::Break(b) => return ControlFlow::Break(b),
+				}
+				cursor = (*cursor).next;
+			}
+		}
+		ControlFlow::Continue(())
+	}
+
+	/// Returns a [`CursorMut`] positioned at the list's head.
+	///
+	/// The cursor walks the list in either direction and splices elements in and
+	/// out at the current position in O(1), using the `prev` links that make the
+	/// list doubly-linked.
+	///
+	/// [`CursorMut`]: struct.CursorMut.html
+	pub fn cursor_mut(&mut self) -> CursorMut<'_, T, N> {
+		let current = self.head;
+		CursorMut { current, list: self }
+	}
+}
+
+/// A mutable cursor over a [`StaticLinkedList`], modeled on the standard
+/// library's `LinkedList` cursor.
+///
+/// The cursor points at one element or, after wrapping past either end, at a
+/// "ghost" position between the tail and the head. From there `move_next`
+/// returns to the head and `move_prev` to the tail.
+///
+/// [`StaticLinkedList`]: struct.StaticLinkedList.html
+pub struct CursorMut<'l, T, const N: usize> where T: Clear {
+	current: *mut Linked<T>,
+	list: &'l mut StaticLinkedList<T, N>,
+}
+
+impl<'l, T, const N: usize> CursorMut<'l, T, N> where T: Clear {
+	/// Moves the cursor to the next element, wrapping past the end to the head.
+	pub fn move_next(&mut self) {
+		if self.current.is_null() {
+			self.current = self.list.head;
+		} else {
+			unsafe {
+				self.current = (*self.current).next;
+			}
+		}
+	}
+
+	/// Moves the cursor to the previous element, wrapping past the front to the tail.
+	pub fn move_prev(&mut self) {
+		if self.current.is_null() {
+			self.current = self.list.tail;
+		} else {
+			unsafe {
+				self.current = (*self.current).prev;
+			}
+		}
+	}
+
+	/// Returns the element the cursor currently points at, or `None` at the ghost position.
+	pub fn current(&mut self) -> Option<&mut T> {
+		if self.current.is_null() {
+			None
+		} else {
+			unsafe {
+				Some(&mut (*self.current).data)
+			}
+		}
+	}
+
+	/// Returns the element after the cursor without moving it.
+	pub fn peek_next(&mut self) -> Option<&mut T> {
+		let next = if self.current.is_null() {
+			self.list.head
+		} else {
+			unsafe { (*self.current).next }
+		};
+
+		if next.is_null() {
+			None
+		} else {
+			unsafe {
+				Some(&mut (*next).data)
+			}
+		}
+	}
+
+	/// Returns the element before the cursor without moving it.
+	pub fn peek_prev(&mut self) -> Option<&mut T> {
+		let prev = if self.current.is_null() {
+			self.list.tail
+		} else {
+			unsafe { (*self.current).prev }
+		};
+
+		if prev.is_null() {
+			None
+		} else {
+			unsafe {
+				Some(&mut (*prev).data)
+			}
+		}
+	}
+
+	/// Inserts `data` directly after the current element. At the ghost position
+	/// this prepends to the head.
+	pub fn insert_after(&mut self, data: T) -> Result<(), Error> {
+		if self.list.array.is_null() {
+			return Err(NullPointer);
+		}
+
+		unsafe {
+			let new = match (*self.list.array).get_free() {
+				Some(new) => new,
+				None => return Err(OutOfSpace),
+			};
+			// SAFETY: `new` is a fresh slot from the free list; its `data` bytes
+			// are uninitialized garbage, so a plain assignment would drop that
+			// garbage as `T`. `write` initializes without reading it.
+			addr_of_mut!((*new).data).write(data);
+
+			if self.current.is_null() {
+				(*new).prev = null_mut();
+				(*new).next = self.list.head;
+				if self.list.head.is_null() {
+					self.list.tail = new;
+				} else {
+					(*self.list.head).prev = new;
+				}
+				self.list.head = new;
+			} else {
+				let next = (*self.current).next;
+				(*new).prev = self.current;
+				(*new).next = next;
+				(*self.current).next = new;
+				if next.is_null() {
+					self.list.tail = new;
+				} else {
+					(*next).prev = new;
+				}
+			}
+
+			self.list.size += 1;
+			Ok(())
+		}
+	}
+
+	/// Inserts `data` directly before the current element. At the ghost position
+	/// this appends to the tail.
+	pub fn insert_before(&mut self, data: T) -> Result<(), Error> {
+		if self.list.array.is_null() {
+			return Err(NullPointer);
+		}
+
+		unsafe {
+			let new = match (*self.list.array).get_free() {
+				Some(new) => new,
+				None => return Err(OutOfSpace),
+			};
+			// SAFETY: `new` is a fresh slot from the free list; its `data` bytes
+			// are uninitialized garbage, so a plain assignment would drop that
+			// garbage as `T`. `write` initializes without reading it.
+			addr_of_mut!((*new).data).write(data);
+
+			if self.current.is_null() {
+				(*new).next = null_mut();
+				(*new).prev = self.list.tail;
+				if self.list.tail.is_null() {
+					self.list.head = new;
+				} else {
+					(*self.list.tail).next = new;
+				}
+				self.list.tail = new;
+			} else {
+				let prev = (*self.current).prev;
+				(*new).next = self.current;
+				(*new).prev = prev;
+				(*self.current).prev = new;
+				if prev.is_null() {
+					self.list.head = new;
+				} else {
+					(*prev).next = new;
+				}
+			}
+
+			self.list.size += 1;
+			Ok(())
+		}
+	}
+
+	/// Unlinks the current element, returns it by value, and hands its slot back
+	/// to the backing array. The cursor then points at the following element (or
+	/// the ghost position if the tail was removed).
+	pub fn remove_current(&mut self) -> Option<T> {
+		if self.current.is_null() {
+			return None;
+		}
+
+		unsafe {
+			let to_remove = self.current;
+			let prev = (*to_remove).prev;
+			let next = (*to_remove).next;
+
+			if prev.is_null() {
+				self.list.head = next;
+			} else {
+				(*prev).next = next;
+			}
+
+			if next.is_null() {
+				self.list.tail = prev;
+			} else {
+				(*next).prev = prev;
+			}
+
+			let data = core::ptr::read(&(*to_remove).data);
+			self.list.size -= 1;
+			self.current = next;
+
+			// `data` was just moved out above; `free_raw` relinks the slot into
+			// the pool without calling `T::clear()` on it, so it can't run
+			// `Clear::clear()` over (and double-drop) the copy `data` now owns.
+			(*self.list.array).free_raw(to_remove.as_mut().unwrap());
+
+			Some(data)
+		}
+	}
 }
 
 /// Ensures that the memory occupied by the list is returned to its backing
 /// array when it goes out of scope.
-impl<'buf, T> Drop for StaticLinkedList<'buf, T> where T: Clear {
+impl<T, const N: usize> Drop for StaticLinkedList<T, N> where T: Clear {
 	fn drop(&mut self) {
 		if self.array.is_null() {
 			return;			
@@ -249,7 +693,7 @@ pub struct StaticLinkedListIterator<'a, T> where T: Clear {
 }
 
 impl<'a, T> StaticLinkedListIterator<'a, T> where T: Clear {
-	fn new(list: &'a StaticLinkedList<T>) -> Self {
+	fn new<const N: usize>(list: &'a StaticLinkedList<T, N>) -> Self {
 		StaticLinkedListIterator {
 			cursor: list.head,
 			_phantom: &core::marker::PhantomData::<T>,
@@ -273,7 +717,7 @@ impl<'a, T> Iterator for StaticLinkedListIterator<'a, T> where T: Clear {
 	}
 }
 
-impl<'l, 'buf, T> IntoIterator for &'l StaticLinkedList<'buf, T> where T: Clear {
+impl<'l, T, const N: usize> IntoIterator for &'l StaticLinkedList<T, N> where T: Clear {
 	type Item = &'l T;
 	type IntoIter = StaticLinkedListIterator<'l, T>;
 
@@ -282,20 +726,154 @@ impl<'l, 'buf, T> IntoIterator for &'l StaticLinkedList<'buf, T> where T: Clear
 	}
 }
 
-/// The backing array for the singly-linked lists. This struct needs to be initialized first
-/// before lists can be created.
-pub struct StaticLinkedListBackingArray<'buf, T> where T: Clear {
-    capacity: usize,
-    free_space: usize,
+/// Iterator over the elements in the list yielding mutable references.
+pub struct StaticLinkedListMutIterator<'a, T> where T: Clear {
+	cursor: *mut Linked<T>,
+
+	_phantom: core::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for StaticLinkedListMutIterator<'a, T> where T: Clear {
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<&'a mut T> {
+		unsafe {
+			if self.cursor.is_null() {
+				None
+			} else {
+				let ret = &mut (*self.cursor).data;
+				self.cursor = (*self.cursor).next;
+				Some(ret)
+			}
+		}
+	}
+}
+
+/// Consuming iterator that drains the list from the head, yielding owned values
+/// and returning each emptied slot to the backing array.
+pub struct StaticLinkedListIntoIterator<T, const N: usize> where T: Clear {
+	list: StaticLinkedList<T, N>,
+}
+
+impl<T, const N: usize> Iterator for StaticLinkedListIntoIterator<T, N> where T: Clear {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		let list = &mut self.list;
+		if list.head.is_null() {
+			return None;
+		}
+
+		unsafe {
+			let head = list.head;
+			let data = core::ptr::read(&(*head).data);
+
+			if head == list.tail {
+				list.tail = null_mut();
+			}
+			list.head = (*head).next;
+			if !list.head.is_null() {
+				(*list.head).prev = null_mut();
+			}
+
+			// `data` was just moved out above; see `free_raw`'s doc comment for
+			// why the ordinary `free()` (which calls `T::clear()`) would
+			// double-free it here.
+			(*list.array).free_raw(head.as_mut().unwrap());
+			list.size -= 1;
+
+			Some(data)
+		}
+	}
+}
+
+impl<T, const N: usize> IntoIterator for StaticLinkedList<T, N> where T: Clear {
+	type Item = T;
+	type IntoIter = StaticLinkedListIntoIterator<T, N>;
+
+	fn into_iter(self) -> StaticLinkedListIntoIterator<T, N> {
+		StaticLinkedListIntoIterator { list: self }
+	}
+}
+
+/// A [`StaticLinkedList`] that may be handed to another thread.
+///
+/// With the `cas` feature the backing array's free list is lock-free, so several
+/// lists carved from the same array can live on different cores. A raw
+/// [`StaticLinkedList`] is still `!Send` because of its interior raw pointers;
+/// wrap it in a `SyncList` to move ownership across a thread boundary once the
+/// array is known to outlive every thread.
+#[cfg(feature = "cas")]
+pub struct SyncList<T, const N: usize>(pub StaticLinkedList<T, N>) where T: Clear;
+
+#[cfg(feature = "cas")]
+unsafe impl<T, const N: usize> Send for SyncList<T, N> where T: Clear + Send {}
+
+#[cfg(feature = "cas")]
+unsafe impl<T, const N: usize> Sync for SyncList<T, N> where T: Clear + Sync {}
+
+#[cfg(feature = "cas")]
+impl<T, const N: usize> SyncList<T, N> where T: Clear {
+	/// Wraps `list` so it can be sent to another thread.
+	///
+	/// # Safety
+	/// The backing array must outlive every thread the wrapped list is used on,
+	/// and no two threads may alias the *same* list concurrently.
+	pub unsafe fn new(list: StaticLinkedList<T, N>) -> Self {
+		SyncList(list)
+	}
+
+	/// Unwraps the inner list.
+	pub fn into_inner(self) -> StaticLinkedList<T, N> {
+		self.0
+	}
+}
+
+/// The backing array for the doubly-linked lists. It owns storage for exactly `N`
+/// entries of type `T`; no raw byte buffer or `capacity_for` byte arithmetic is
+/// needed. This struct must be created first before lists can be carved from it.
+///
+/// Because the storage is owned inline and the lists hold raw pointers into it,
+/// the array is effectively pinned once [`new_list()`] has been called: moving it
+/// afterwards dangles every list. [`new_list()`] makes a best-effort attempt to
+/// catch this at runtime (see the crate-level "Pinning invariant"), but the
+/// invariant is not compile-time enforced — it's on the caller.
+///
+/// [`new_list()`]: struct.StaticLinkedListBackingArray.html#method.new_list
+pub struct StaticLinkedListBackingArray<T, const N: usize> where T: Clear {
+    storage: [MaybeUninit<Linked<T>>; N],
+
+    self_addr: usize,	// this array's own address as of the first new_list() call;
+    			// used by ensure_init() to catch the array being moved afterwards
+
+    #[cfg(not(feature = "cas"))]
     lists: usize,		// number of lists relying on this array
+    // `lists` is decremented from `drop_list()`, which `SyncList`-wrapped lists
+    // can run on any thread; a plain `usize` there would race. `initialized`
+    // is only ever touched from `ensure_init()`, which `new_list()` can only
+    // reach through `&mut self`, so it stays a plain bool even under `cas`.
+    #[cfg(feature = "cas")]
+    lists: AtomicUsize,	// number of lists relying on this array
+    initialized: bool,	// whether the free list has been threaded yet
 
-    buf: &'buf mut [u8], // let the array own the buffer
-    free: *mut Linked<T>, // pointer into free linked entries in the buffer
+    #[cfg(not(feature = "cas"))]
+    free_space: usize,
+    #[cfg(not(feature = "cas"))]
+    free: *mut Linked<T>, // pointer into free linked entries in the storage
+
+    // Lock-free Treiber stack of free entries (see the `cas` module-level notes).
+    #[cfg(feature = "cas")]
+    free_space: AtomicUsize,
+    #[cfg(feature = "cas")]
+    free: AtomicUsize, // tagged head of the free list
 }
 
-impl<'buf, T> StaticLinkedListBackingArray<'buf, T> where T: Clear {
-	/// Convenience function calculating the bytes required for an array of `n` elements of type `T` 
-	/// *plus* the list's metadata (i.e. `next` pointer). **Requires the** `const_fn` **feature.**
+impl<T, const N: usize> StaticLinkedListBackingArray<T, N> where T: Clear {
+	/// Creates a new backing array with capacity for exactly `N` elements of type `T`.
+	///
+	/// The storage starts uninitialized; the free list is threaded lazily the first
+	/// time a list is created (see [`new_list()`]), so that it references the array's
+	/// final address rather than the temporary `new()` returns.
 	///
 	/// # Example:
 	///  ```
@@ -309,64 +887,109 @@ impl<'buf, T> StaticLinkedListBackingArray<'buf, T> where T: Clear {
 	///  	}
 	///  }
 	///
-	///  // Reserve memory for 20 instances of U32Clear plus list metadata. This executes at compile time!
-	///  const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(20);
-	///  let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	///  let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	///  // Room for exactly 20 instances of U32Clear. Runs on stable Rust.
+	///  let mut array = StaticLinkedListBackingArray::<U32Clear, 20>::new().unwrap();
 	///  ```
-	pub const fn capacity_for(n: usize) -> usize {
-		n * size_of::<Linked<T>>()
-	}
-
-	/// Creates a new backing array for linked lists from the given `buf`.
-	/// The second argument `bytes` *must* be the size of `buf` in bytes!
-	/// 
-	/// # Buffer size
-	/// Note that the linked list needs some space for metadata (pointers to the next element).
-	/// Consequently, for `n` elements of size `s`, it does not suffice to allocate `s * n` bytes!
-	/// To allocate the exact needed amount of memory, use [`capacity_for()`].
 	///
-	/// [`capacity_for()`]: ../static_linkedlist/struct.StaticLinkedListBackingArray.html#method.capacity_for
-	pub fn new(buf: &'buf mut [u8]) -> Result<Self, Error> {
+	/// [`new_list()`]: struct.StaticLinkedListBackingArray.html#method.new_list
+	pub const fn new() -> Result<Self, Error> {
 		if core::mem::size_of::<T>() == 0 {
-			Err(ZeroSizedType)
-		} else {
-			let linkedbuf = buf.as_mut_ptr() as *mut Linked<T>;
-			let capacity = buf.len() / size_of::<Linked<T>>();
-			
-			// Initialize memory:
-			// - create linked list of free blocks by setting 'next' pointer to adjacent memory blocks
-			// - clear list content calling 'clear()' on every entry
-			unsafe {
-				let mut cursor = linkedbuf;
-				for _i in 0..(capacity - 1) {
-					(*cursor).next = cursor.add(1);
-					cursor.as_mut().unwrap().clear();
-					cursor = (*cursor).next;
-				}
-				(*cursor).next = null_mut();
-			}
+			return Err(ZeroSizedType);
+		}
+
+		// `[MaybeUninit<_>; N]` is itself always initialized, so this is sound.
+		let storage = unsafe { MaybeUninit::uninit().assume_init() };
 
+		#[cfg(not(feature = "cas"))]
+		{
 			Ok(StaticLinkedListBackingArray {
-				capacity: capacity,
-				free_space: capacity,
+				storage,
+				self_addr: 0,
 				lists: 0,
-				buf: buf,
-				free: linkedbuf,
+				initialized: false,
+				free_space: N,
+				free: null_mut(),
 			})
 		}
+		#[cfg(feature = "cas")]
+		{
+			Ok(StaticLinkedListBackingArray {
+				storage,
+				self_addr: 0,
+				lists: AtomicUsize::new(0),
+				initialized: false,
+				free_space: AtomicUsize::new(N),
+				free: AtomicUsize::new(0),
+			})
+		}
+	}
+
+	/// Threads the free list through the typed storage the first time it is needed,
+	/// pointing each slot's `next` at the following slot and null-terminating the last.
+	/// Deferred until the array sits at its final address so the links stay valid.
+	///
+	/// Every call (not just the first) also checks the array's current address
+	/// against the one recorded here the first time: nothing can stop a move
+	/// that invalidates the lists already carved from this array, but a second
+	/// `new_list()` call after such a move panics loudly here instead of
+	/// letting the first list's pointers dangle silently. See the crate-level
+	/// "Pinning invariant".
+	fn ensure_init(&mut self) {
+		let current_addr = self as *const Self as usize;
+
+		if self.initialized {
+			assert_eq!(
+				self.self_addr, current_addr,
+				"StaticLinkedListBackingArray moved after new_list() was called; \
+				 see the crate-level \"Pinning invariant\" in the crate docs"
+			);
+			return;
+		}
+		self.initialized = true;
+		self.self_addr = current_addr;
+
+		if N == 0 {
+			return;
+		}
+
+		let base = self.storage.as_mut_ptr() as *mut Linked<T>;
+		unsafe {
+			let mut i = 0;
+			while i < N - 1 {
+				(*base.add(i)).next = base.add(i + 1);
+				i += 1;
+			}
+			(*base.add(N - 1)).next = null_mut();
+		}
+
+		#[cfg(not(feature = "cas"))]
+		{
+			self.free = base;
+		}
+		#[cfg(feature = "cas")]
+		{
+			self.free.store(pack(base, 0), Relaxed);
+		}
 	}
 
-	/// Returns the backing array's capacity.
+	/// Returns the backing array's capacity, i.e. `N`.
 	pub fn capacity(&self) -> usize {
-		self.capacity
+		N
 	}
 
 	/// Returns the remaining space for element sof type T in the array.
+	#[cfg(not(feature = "cas"))]
 	pub fn free_space(&self) -> usize {
 		self.free_space
 	}
 
+	/// Returns the remaining space for element sof type T in the array.
+	#[cfg(feature = "cas")]
+	pub fn free_space(&self) -> usize {
+		self.free_space.load(Relaxed)
+	}
+
+	#[cfg(not(feature = "cas"))]
 	unsafe fn get_free(&mut self) -> Option<*mut Linked<T>> {
 		if self.free.is_null() {
 			None
@@ -379,40 +1002,144 @@ impl<'buf, T> StaticLinkedListBackingArray<'buf, T> where T: Clear {
 		}
 	}
 
+	// Lock-free pop off the Treiber stack. Shared (`&self`) so a single backing
+	// array can feed lists running on several cores without a mutex.
+	#[cfg(feature = "cas")]
+	unsafe fn get_free(&self) -> Option<*mut Linked<T>> {
+		loop {
+			let old = self.free.load(Acquire);
+			let head = unpack_ptr::<T>(old);
+			if head.is_null() {
+				return None;
+			}
+
+			let next = next_atomic(head).load(Acquire);
+			let new = pack(next, unpack_tag::<T>(old).wrapping_add(1));
+
+			if self.free.compare_exchange_weak(old, new, Release, Relaxed).is_ok() {
+				self.free_space.fetch_sub(1, Relaxed);
+				return Some(head);
+			}
+			// Lost the race (or an ABA-stale tag): reload and retry.
+		}
+	}
+
+	#[cfg(not(feature = "cas"))]
 	fn free(&mut self, link: &mut Linked<T>) {
-		// TODO: make sure link points to a link in our buffer.
 		link.clear();
+		self.free_raw(link);
+	}
+
+	// Lock-free push onto the Treiber stack.
+	#[cfg(feature = "cas")]
+	fn free(&self, link: &mut Linked<T>) {
+		link.clear();
+		self.free_raw(link);
+	}
+
+	// Returns `link`'s slot to the pool without calling `T::clear()`.
+	//
+	// Used when the caller has already moved the slot's `data` out via
+	// `ptr::read` and handed ownership to someone else (e.g. `remove_current`,
+	// `StaticLinkedListIntoIterator::next`): calling `clear()` there would run the user's
+	// `Clear` impl over a live bitwise duplicate of a value the caller now
+	// owns, and a `clear()` that resets by reassignment (`self.x =
+	// Default::default()`) drops that duplicate's old fields out from under
+	// the caller's copy — a double free.
+	#[cfg(not(feature = "cas"))]
+	fn free_raw(&mut self, link: &mut Linked<T>) {
+		// TODO: make sure link points to a link in our buffer.
 		link.next = self.free;
 		self.free = link;
 		self.free_space += 1;
 	}
 
+	// Lock-free push onto the Treiber stack, without calling `T::clear()`. See
+	// the non-`cas` `free_raw` above for why this exists.
+	#[cfg(feature = "cas")]
+	fn free_raw(&self, link: &mut Linked<T>) {
+		// TODO: make sure link points to a link in our buffer.
+		let node = link as *mut Linked<T>;
+
+		loop {
+			let old = self.free.load(Acquire);
+			unsafe { next_atomic(node).store(unpack_ptr::<T>(old), Release); }
+			let new = pack(node, unpack_tag::<T>(old).wrapping_add(1));
+
+			if self.free.compare_exchange_weak(old, new, Release, Relaxed).is_ok() {
+				self.free_space.fetch_add(1, Relaxed);
+				return;
+			}
+		}
+	}
+
 	/// Returns `true` if the array is full.
 	pub fn is_full(&self) -> bool {
-		self.free_space == 0
+		self.free_space() == 0
 	}
 
 	/// Return the number of lists backed by this array.
+	#[cfg(not(feature = "cas"))]
 	pub fn lists(&self) -> usize {
 		self.lists
 	}
 
+	/// Return the number of lists backed by this array.
+	#[cfg(feature = "cas")]
+	pub fn lists(&self) -> usize {
+		self.lists.load(Relaxed)
+	}
+
+	#[cfg(not(feature = "cas"))]
 	fn drop_list(&mut self) {
 		self.lists -= 1;
 	}
 
+	// `SyncList`-wrapped lists can be dropped from any thread, so this takes
+	// `&self` and updates the count atomically rather than requiring the
+	// exclusive access a plain `usize -= 1` would need.
+	#[cfg(feature = "cas")]
+	fn drop_list(&self) {
+		self.lists.fetch_sub(1, Relaxed);
+	}
+
 	/// Creates a new [`StaticLinkedList`] backed by the memory of this array.
 	///
+	/// The first call threads the free list through this array's storage at its
+	/// current address. The array must therefore stay put from here on — moving it
+	/// after this call dangles the returned list (see the crate-level "Pinning
+	/// invariant").
+	///
 	/// [`StaticLinkedList`]: struct.StaticLinkedList.html
-	pub fn new_list(&mut self) -> StaticLinkedList<'buf, T> {
-		self.lists += 1;
+	pub fn new_list(&mut self) -> StaticLinkedList<T, N> {
+		self.ensure_init();
+		#[cfg(not(feature = "cas"))]
+		{ self.lists += 1; }
+		#[cfg(feature = "cas")]
+		{ self.lists.fetch_add(1, Relaxed); }
 		StaticLinkedList {
 			size: 0,
 			head: core::ptr::null_mut(),
 			tail: core::ptr::null_mut(),
-			array: self as *mut StaticLinkedListBackingArray<'buf, T>
+			array: self as *mut StaticLinkedListBackingArray<T, N>
 		}
 	}
+
+	/// Creates a fresh [`StaticLinkedList`] and fills it from `iter`.
+	///
+	/// Convenience wrapper around [`new_list()`] + [`try_extend()`]. Returns the
+	/// filled list, or `Err(OutOfSpace)` if `iter` did not fit — in which case the
+	/// partially filled list is dropped and its slots returned to the pool.
+	///
+	/// [`new_list()`]: struct.StaticLinkedListBackingArray.html#method.new_list
+	/// [`try_extend()`]: struct.StaticLinkedList.html#method.try_extend
+	pub fn new_list_from<I>(&mut self, iter: I) -> Result<StaticLinkedList<T, N>, Error>
+		where I: IntoIterator<Item = T>
+	{
+		let mut list = self.new_list();
+		list.try_extend(iter)?;
+		Ok(list)
+	}
 }
 
 #[cfg(test)]
@@ -1,3 +1,8 @@
+// `#![no_std]` at the crate root suppresses the implicit `extern crate std`;
+// bring it back just for the test binary, which needs it for `std::thread`.
+#[cfg(feature = "cas")]
+extern crate std;
+
 use super::*;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -17,61 +22,51 @@ fn test_forbid_zst() {
 		fn clear(&mut self) {}
 	}
 
-	let mut buf: [u8; 1] = [0; 1];
-	if let Err(error) = StaticLinkedListBackingArray::<ZeroSizedType>::new(&mut buf) {
+	if let Err(error) = StaticLinkedListBackingArray::<ZeroSizedType, 1>::new() {
 		assert!(error == ZeroSizedType);
 	} else {
 		assert!(false);
 	}
 }
 
-#[test]
-fn test_capacity_for() {
-	let wrapped_data_size = size_of::<Linked<U32Clear>>();
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(10);
-	let _buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-
-	assert_eq!(BUF_SIZE, 10 * wrapped_data_size);
-}
-
 #[test]
 fn test_capacity() {
-	const BUF_1_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(2);
-	const BUF_2_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(2) + 1;
-	let mut buf_1: [u8;  BUF_1_SIZE] = [0; BUF_1_SIZE];
-	let mut buf_2: [u8; BUF_2_SIZE] = [0; BUF_2_SIZE];
+	let array_1 = StaticLinkedListBackingArray::<U32Clear, 2>::new().unwrap();
+	assert_eq!(array_1.capacity(), 2);
 
-	let array_1 = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf_1).unwrap();
-	assert_eq!(array_1.capacity(), BUF_1_SIZE / size_of::<Linked<U32Clear>>());
-
-	let array_2 = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf_2).unwrap();
-	assert_eq!(array_2.capacity(), BUF_2_SIZE / size_of::<Linked<U32Clear>>());
+	let array_2 = StaticLinkedListBackingArray::<U32Clear, 7>::new().unwrap();
+	assert_eq!(array_2.capacity(), 7);
 }
 
 #[test]
 fn test_links() {
-	let mut buf: [u8; 80] = [0; 80];
-
-	let array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
-
-	let mut cnt = 0;
-	let mut cursor = array.free;
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 5>::new().unwrap();
+	// Threading the free list is deferred until the first list is created.
+	let _list = array.new_list();
 
-	while !cursor.is_null() {
-		cnt += 1;
-		unsafe {
-			cursor = (*cursor).next;
+	// Walking the raw free list pokes the plain-pointer head, which only exists
+	// without the `cas` feature; the atomic free list is exercised separately.
+	#[cfg(not(feature = "cas"))]
+	{
+		let mut cnt = 0;
+		let mut cursor = array.free;
+
+		while !cursor.is_null() {
+			cnt += 1;
+			unsafe {
+				cursor = (*cursor).next;
+			}
 		}
+
+		assert_eq!(cnt, array.capacity());
 	}
 
-	assert_eq!(cnt, array.capacity());
+	assert_eq!(array.free_space(), array.capacity());
 }
 
 #[test]
 fn test_append() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(10);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
 	let mut list = array.new_list();
 
 	let mut cnt = 0;
@@ -82,7 +77,7 @@ fn test_append() {
 	}
 
 	assert_eq!(cnt as usize, array.capacity());
-	assert!(array.free.is_null());
+	assert!(array.is_full());
 	unsafe {
 		assert_eq!((*list.tail).data.0, cnt - 1);
 		assert_eq!((*list.head).data.0, 0);
@@ -102,9 +97,7 @@ fn test_append() {
 
 #[test]
 fn test_prepend() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(10);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
 	let mut list = array.new_list();
 
 	let mut cnt = 10;
@@ -114,28 +107,21 @@ fn test_prepend() {
 		cnt -= 1;
 	}
 
-	assert!(array.free.is_null());
+	assert!(array.is_full());
 	assert_eq!(cnt, 0);
 }
 
 #[test]
 fn test_remove_all_satisfying_head() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(3);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 3>::new().unwrap();
 	let mut list = array.new_list();
 
-	let val_1 = U32Clear(1);
-	let val_2 = U32Clear(2);
-	let val_3 = U32Clear(3);
-
-	list.append(val_1).unwrap();
-	list.append(val_2).unwrap();
-	list.append(val_3).unwrap();
+	list.append(U32Clear(1)).unwrap();
+	list.append(U32Clear(2)).unwrap();
+	list.append(U32Clear(3)).unwrap();
 
 	assert!(array.is_full());
-	assert!(array.free.is_null());
-	assert_eq!(array.free_space, 0);
+	assert_eq!(array.free_space(), 0);
 
 	list.remove_all_satisfying(|entry| -> bool {
 		entry.0 == 1
@@ -153,22 +139,15 @@ fn test_remove_all_satisfying_head() {
 
 #[test]
 fn test_remove_all_satisfying_tail() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(3);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 3>::new().unwrap();
 	let mut list = array.new_list();
 
-	let val_1 = U32Clear(1);
-	let val_2 = U32Clear(2);
-	let val_3 = U32Clear(3);
-
-	list.append(val_1).unwrap();
-	list.append(val_2).unwrap();
-	list.append(val_3).unwrap();
+	list.append(U32Clear(1)).unwrap();
+	list.append(U32Clear(2)).unwrap();
+	list.append(U32Clear(3)).unwrap();
 
 	assert!(array.is_full());
-	assert!(array.free.is_null());
-	assert_eq!(array.free_space, 0);
+	assert_eq!(array.free_space(), 0);
 
 	list.remove_all_satisfying(|entry| -> bool {
 		entry.0 == 3
@@ -186,22 +165,15 @@ fn test_remove_all_satisfying_tail() {
 
 #[test]
 fn test_remove_all_satisfying_inner() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(3);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 3>::new().unwrap();
 	let mut list = array.new_list();
 
-	let val_1 = U32Clear(1);
-	let val_2 = U32Clear(2);
-	let val_3 = U32Clear(3);
-
-	list.append(val_1).unwrap();
-	list.append(val_2).unwrap();
-	list.append(val_3).unwrap();
+	list.append(U32Clear(1)).unwrap();
+	list.append(U32Clear(2)).unwrap();
+	list.append(U32Clear(3)).unwrap();
 
 	assert!(array.is_full());
-	assert!(array.free.is_null());
-	assert_eq!(array.free_space, 0);
+	assert_eq!(array.free_space(), 0);
 
 	list.remove_all_satisfying(|entry| -> bool {
 		entry.0 == 2
@@ -219,9 +191,7 @@ fn test_remove_all_satisfying_inner() {
 
 #[test]
 fn test_iterator() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(20);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 20>::new().unwrap();
 	let mut list = array.new_list();
 
 	let mut cnt = 0;
@@ -231,7 +201,7 @@ fn test_iterator() {
 		cnt += 1;
 	}
 
-	assert!(array.free.is_null());
+	assert!(array.is_full());
 	assert_eq!(cnt, 20);
 
 	cnt = 0;
@@ -242,11 +212,133 @@ fn test_iterator() {
 	}
 }
 
+#[test]
+fn test_cursor_insert_and_remove() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	list.append(U32Clear(1)).unwrap();
+	list.append(U32Clear(3)).unwrap();
+
+	// Splice a 2 between the 1 and the 3.
+	let mut cursor = list.cursor_mut();
+	assert_eq!(cursor.current().unwrap().0, 1);
+	cursor.insert_after(U32Clear(2)).unwrap();
+
+	assert_eq!(list.size(), 3);
+	for (i, entry) in (&list).into_iter().enumerate() {
+		assert_eq!(entry.0, i as u32 + 1);
+	}
+
+	// Remove the head via the cursor and get it back by value.
+	let mut cursor = list.cursor_mut();
+	let removed = cursor.remove_current().unwrap();
+	assert_eq!(removed.0, 1);
+	assert_eq!(cursor.current().unwrap().0, 2);
+	assert_eq!(list.size(), 2);
+	assert_eq!(list.head().unwrap().0, 2);
+}
+
+#[test]
+fn test_cursor_wraps_past_ghost() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	list.append(U32Clear(1)).unwrap();
+	list.append(U32Clear(2)).unwrap();
+
+	let mut cursor = list.cursor_mut();
+	assert_eq!(cursor.current().unwrap().0, 1);
+	cursor.move_next();
+	assert_eq!(cursor.current().unwrap().0, 2);
+	cursor.move_next(); // onto the ghost position
+	assert!(cursor.current().is_none());
+	assert_eq!(cursor.peek_next().unwrap().0, 1);
+	cursor.move_next(); // wraps back to the head
+	assert_eq!(cursor.current().unwrap().0, 1);
+	cursor.move_prev(); // back onto the single ghost position
+	assert!(cursor.current().is_none());
+	cursor.move_prev(); // ghost -> tail
+	assert_eq!(cursor.current().unwrap().0, 2);
+}
+
+#[test]
+fn test_iter_mut() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	for i in 0..5 {
+		list.append(U32Clear(i)).unwrap();
+	}
+
+	for entry in list.iter_mut() {
+		entry.0 += 10;
+	}
+
+	for (i, entry) in (&list).into_iter().enumerate() {
+		assert_eq!(entry.0, i as u32 + 10);
+	}
+}
+
+#[test]
+fn test_into_iter_reclaims_pool() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let capacity = array.capacity();
+
+	let mut list = array.new_list();
+	for i in 0..5 {
+		list.append(U32Clear(i)).unwrap();
+	}
+	assert_eq!(array.free_space(), capacity - 5);
+
+	let mut cnt = 0;
+	for entry in list {
+		assert_eq!(entry.0, cnt);
+		cnt += 1;
+	}
+	assert_eq!(cnt, 5);
+
+	// Draining the list reclaimed every slot it held.
+	assert_eq!(array.free_space(), capacity);
+}
+
+#[test]
+fn test_try_extend() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	let inserted = list.try_extend((0..5).map(U32Clear)).unwrap();
+	assert_eq!(inserted, 5);
+	assert_eq!(list.size(), 5);
+
+	for (i, entry) in (&list).into_iter().enumerate() {
+		assert_eq!(entry.0, i as u32);
+	}
+}
+
+#[test]
+fn test_try_extend_out_of_space() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 3>::new().unwrap();
+	let mut list = array.new_list();
+
+	// More elements than the pool can hold: the first three land, the rest error.
+	assert_eq!(list.try_extend((0..10).map(U32Clear)), Err(OutOfSpace));
+	assert_eq!(list.size(), 3);
+}
+
+#[test]
+fn test_new_list_from() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let list = array.new_list_from((0..4).map(U32Clear)).unwrap();
+
+	assert_eq!(list.size(), 4);
+	assert_eq!(list.head().unwrap().0, 0);
+	assert_eq!(list.tail().unwrap().0, 3);
+}
+
 #[test]
 fn test_free() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(20);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 20>::new().unwrap();
 	let mut list = array.new_list();
 
 	list.append(U32Clear(1)).unwrap();
@@ -265,10 +357,8 @@ fn test_free() {
 
 #[test]
 fn test_list_drop() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(20);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
-	
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 20>::new().unwrap();
+
 	let initial_array_capacity = array.capacity();
 	assert_eq!(initial_array_capacity, array.free_space());
 	assert_eq!(array.lists(), 0);
@@ -289,9 +379,7 @@ fn test_list_drop() {
 
 #[test]
 fn test_at() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(10);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
 	let mut list = array.new_list();
 
 	for i in 0..10 {
@@ -303,19 +391,94 @@ fn test_at() {
 	}
 
 	let mut error = false;
-	if let Err(e) = list.at(10) {	
+	if let Err(e) = list.at(10) {
 		error = true;
 		assert_eq!(e, IndexOutOfBounds);
 	}
 	assert!(error);
 }
 
+#[test]
+fn test_insert_at() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	list.append(U32Clear(0)).unwrap();
+	list.append(U32Clear(3)).unwrap();
+
+	list.insert_at(0, U32Clear(100)).unwrap(); // prepend
+	list.insert_at(list.size(), U32Clear(4)).unwrap(); // append
+	list.insert_at(2, U32Clear(1)).unwrap(); // interior
+
+	let expected = [100u32, 0, 1, 3, 4];
+	assert_eq!(list.size(), expected.len());
+	for (i, entry) in (&list).into_iter().enumerate() {
+		assert_eq!(entry.0, expected[i]);
+	}
+
+	assert_eq!(list.insert_at(99, U32Clear(7)), Err(IndexOutOfBounds));
+}
+
+#[test]
+fn test_remove_range() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	for i in 0..6 {
+		list.append(U32Clear(i)).unwrap();
+	}
+
+	// Remove the inner run [2, 3, 4].
+	assert_eq!(list.remove_range(2, 3).unwrap(), 3);
+	let expected = [0u32, 1, 5];
+	assert_eq!(list.size(), expected.len());
+	for (i, entry) in (&list).into_iter().enumerate() {
+		assert_eq!(entry.0, expected[i]);
+	}
+	assert_eq!(list.head().unwrap().0, 0);
+	assert_eq!(list.tail().unwrap().0, 5);
+
+	// A length reaching past the tail is clamped.
+	assert_eq!(list.remove_range(1, 99).unwrap(), 2);
+	assert_eq!(list.size(), 1);
+	assert_eq!(list.tail().unwrap().0, 0);
+
+	assert_eq!(list.remove_range(5, 1), Err(IndexOutOfBounds));
+}
+
+#[test]
+fn test_try_for_each() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+	let mut list = array.new_list();
+
+	for i in 0..5 {
+		list.append(U32Clear(i)).unwrap();
+	}
+
+	// Short-circuit: find the first element equal to 3.
+	let found = list.try_for_each(|entry| {
+		if entry.0 == 3 {
+			ControlFlow::Break(entry.0)
+		} else {
+			ControlFlow::Continue(())
+		}
+	});
+	assert_eq!(found, ControlFlow::Break(3));
+
+	// Runs to completion when the closure never breaks.
+	let mut sum = 0;
+	let done = list.try_for_each(|entry| -> ControlFlow<()> {
+		sum += entry.0;
+		ControlFlow::Continue(())
+	});
+	assert_eq!(done, ControlFlow::Continue(()));
+	assert_eq!(sum, 10);
+}
+
 #[test]
 fn test_head_and_tail_getters() {
-	const BUF_SIZE: usize = StaticLinkedListBackingArray::<U32Clear>::capacity_for(10);
-	let mut buf: [u8; BUF_SIZE] = [0; BUF_SIZE];
-	let mut array = StaticLinkedListBackingArray::<U32Clear>::new(&mut buf).unwrap();
-	
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 10>::new().unwrap();
+
 	{
 		let mut list = array.new_list();
 		list.append(U32Clear(1)).unwrap();
@@ -328,7 +491,7 @@ fn test_head_and_tail_getters() {
 		list.append(U32Clear(1)).unwrap();
 		list.append(U32Clear(2)).unwrap();
 		assert_eq!(list.head().unwrap().0, 1);
-		assert_eq!(list.tail().unwrap().0, 2);	
+		assert_eq!(list.tail().unwrap().0, 2);
 	}
 
 	{
@@ -336,4 +499,82 @@ fn test_head_and_tail_getters() {
 		assert_eq!(list.head(), None);
 		assert_eq!(list.tail(), None);
 	}
-}
\ No newline at end of file
+}
+
+// Exercises the atomic Treiber-stack free list: every append pops a node off it
+// and every removal pushes one back, so filling and draining drives the CAS
+// pop/push loops and the ABA tag threading directly.
+#[cfg(feature = "cas")]
+#[test]
+fn test_cas_free_list_pop_push() {
+	let mut array = StaticLinkedListBackingArray::<U32Clear, 4>::new().unwrap();
+	let mut list = array.new_list();
+
+	// Drain the free list via pop.
+	for i in 0..4 {
+		list.append(U32Clear(i)).unwrap();
+	}
+	assert!(array.is_full());
+	assert_eq!(array.free_space(), 0);
+	assert_eq!(list.append(U32Clear(99)), Err(OutOfSpace));
+
+	// Push every slot back, then pop them all again to confirm the recycled
+	// nodes carry fresh tags and stay poppable.
+	while list.size() > 0 {
+		list.remove_head().unwrap();
+	}
+	assert_eq!(array.free_space(), 4);
+
+	for i in 0..4 {
+		list.append(U32Clear(i)).unwrap();
+	}
+	assert_eq!(list.size(), 4);
+	assert_eq!(array.free_space(), 0);
+}
+
+// A list wrapped in `SyncList` is `Send`/`Sync`, so it can cross a thread
+// boundary once the backing array is known to outlive it.
+#[cfg(feature = "cas")]
+#[test]
+fn test_sync_list_is_send() {
+	fn assert_send_sync<S: Send + Sync>() {}
+	assert_send_sync::<SyncList<U32Clear, 4>>();
+}
+
+// Exercises the free list under genuine concurrency: several threads each
+// hammer append/remove_head on their own SyncList-wrapped list, all carved
+// from the same array, so the CAS retry loop and the ABA tag actually have to
+// arbitrate real contention instead of a single thread's sequential pop/push.
+// Every list is also dropped from inside its worker thread, exercising
+// drop_list()'s atomic decrement under the same contention.
+#[cfg(feature = "cas")]
+#[test]
+fn test_cas_free_list_concurrent_threads() {
+	const THREADS: usize = 8;
+	const ITERS: usize = 2000;
+	const CAPACITY: usize = THREADS;
+
+	let mut array = StaticLinkedListBackingArray::<U32Clear, CAPACITY>::new().unwrap();
+
+	let lists: std::vec::Vec<_> = (0..THREADS)
+		.map(|_| unsafe { SyncList::new(array.new_list()) })
+		.collect();
+
+	std::thread::scope(|scope| {
+		for list in lists {
+			scope.spawn(move || {
+				let mut list = list.into_inner();
+				for i in 0..ITERS {
+					list.append(U32Clear(i as u32)).unwrap();
+					list.remove_head().unwrap();
+				}
+			});
+		}
+	});
+
+	// Every worker thread's list has been dropped, so every slot it ever
+	// pulled off the free list (and every list itself, via drop_list()) is
+	// back in the pool.
+	assert_eq!(array.free_space(), CAPACITY);
+	assert_eq!(array.lists(), 0);
+}